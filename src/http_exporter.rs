@@ -0,0 +1,243 @@
+//! HTTP metrics exporter.
+//!
+//! Serves the latest background-refreshed snapshots so Prometheus/Grafana can
+//! scrape the crate like a node exporter. Data is always pulled through the
+//! shared request channel, so it reflects the live `System` rather than a
+//! separate refresh.
+
+use std::{convert::Infallible, fmt::Write, net::SocketAddr, thread};
+
+use futures::channel::oneshot;
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Response, Server, StatusCode,
+};
+use serde::Serialize;
+
+use crate::{Cpu, Disk, Memory, Network, Process, Request};
+
+/// Spawns the exporter on a dedicated thread running its own runtime.
+pub(crate) fn spawn(addr: SocketAddr, req_tx: flume::Sender<Request>) {
+    thread::spawn(move || {
+        let runtime = match tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        {
+            Ok(runtime) => runtime,
+            Err(e) => {
+                log::error!("http exporter: failed to build runtime: {e}");
+                return;
+            }
+        };
+        runtime.block_on(async move {
+            let make_service = make_service_fn(move |_conn| {
+                let req_tx = req_tx.clone();
+                async move {
+                    Ok::<_, Infallible>(service_fn(move |req| {
+                        handle(req, req_tx.clone())
+                    }))
+                }
+            });
+            if let Err(e) = Server::bind(&addr).serve(make_service).await {
+                log::error!("http exporter: server error: {e}");
+            }
+        });
+    });
+}
+
+async fn handle(
+    req: hyper::Request<Body>,
+    req_tx: flume::Sender<Request>,
+) -> Result<Response<Body>, Infallible> {
+    let response = match req.uri().path() {
+        "/metrics.json" => {
+            let snapshot = Snapshot::collect(&req_tx).await;
+            match serde_json::to_vec(&snapshot) {
+                Ok(body) => Response::builder()
+                    .header("content-type", "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+                Err(e) => internal_error(e),
+            }
+        }
+        "/metrics" => {
+            let snapshot = Snapshot::collect(&req_tx).await;
+            Response::builder()
+                .header("content-type", "text/plain; version=0.0.4")
+                .body(Body::from(snapshot.to_prometheus()))
+                .unwrap()
+        }
+        _ => Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Body::from("not found"))
+            .unwrap(),
+    };
+    Ok(response)
+}
+
+fn internal_error(e: impl std::fmt::Display) -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::INTERNAL_SERVER_ERROR)
+        .body(Body::from(e.to_string()))
+        .unwrap()
+}
+
+#[derive(Serialize)]
+struct Snapshot {
+    cpu: Vec<Cpu>,
+    memory: Memory,
+    network: Vec<Network>,
+    disk: Vec<Disk>,
+    processes: Vec<Process>,
+}
+
+impl Snapshot {
+    async fn collect(req_tx: &flume::Sender<Request>) -> Self {
+        Self {
+            cpu: request(req_tx, |tx| Request::Cpu { tx }).await.unwrap_or_default(),
+            memory: request(req_tx, |tx| Request::Memory { tx })
+                .await
+                .unwrap_or_default(),
+            network: request(req_tx, |tx| Request::Network { tx })
+                .await
+                .unwrap_or_default(),
+            disk: request(req_tx, |tx| Request::Disk { tx }).await.unwrap_or_default(),
+            processes: request(req_tx, |tx| Request::Process { tx })
+                .await
+                .unwrap_or_default(),
+        }
+    }
+
+    fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        for cpu in &self.cpu {
+            let _ = writeln!(
+                out,
+                "system_cpu_usage{{cpu=\"{}\"}} {}",
+                cpu.name, cpu.usage
+            );
+        }
+        let _ = writeln!(out, "system_memory_total_bytes {}", self.memory.total);
+        let _ = writeln!(out, "system_memory_used_bytes {}", self.memory.used);
+        let _ = writeln!(
+            out,
+            "system_memory_available_bytes {}",
+            self.memory.available
+        );
+        let _ = writeln!(out, "system_memory_free_bytes {}", self.memory.free);
+        let _ = writeln!(out, "system_swap_total_bytes {}", self.memory.swap.total);
+        let _ = writeln!(out, "system_swap_used_bytes {}", self.memory.swap.used);
+        let _ = writeln!(out, "system_swap_free_bytes {}", self.memory.swap.free);
+        for net in &self.network {
+            let iface = &net.name;
+            let _ = writeln!(
+                out,
+                "system_network_received_bytes{{iface=\"{iface}\"}} {}",
+                net.received
+            );
+            let _ = writeln!(
+                out,
+                "system_network_transmitted_bytes{{iface=\"{iface}\"}} {}",
+                net.transmitted
+            );
+            let _ = writeln!(
+                out,
+                "system_network_received_bytes_total{{iface=\"{iface}\"}} {}",
+                net.total_received
+            );
+            let _ = writeln!(
+                out,
+                "system_network_transmitted_bytes_total{{iface=\"{iface}\"}} {}",
+                net.total_transmitted
+            );
+        }
+        for disk in &self.disk {
+            let mount = disk.mount_point.display();
+            let _ = writeln!(
+                out,
+                "system_disk_total_bytes{{mount=\"{mount}\"}} {}",
+                disk.total_space
+            );
+            let _ = writeln!(
+                out,
+                "system_disk_available_bytes{{mount=\"{mount}\"}} {}",
+                disk.available_space
+            );
+        }
+        let _ = writeln!(out, "system_processes {}", self.processes.len());
+        out
+    }
+}
+
+async fn request<T, F>(req_tx: &flume::Sender<Request>, make: F) -> Option<T>
+where
+    F: FnOnce(oneshot::Sender<T>) -> Request,
+{
+    let (tx, rx) = oneshot::channel();
+    req_tx.send_async(make(tx)).await.ok()?;
+    rx.await.ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::Swap;
+
+    #[test]
+    fn prometheus_renders_labelled_series_and_counters() {
+        let snapshot = Snapshot {
+            cpu: vec![Cpu {
+                name: "cpu0".to_string(),
+                usage: 12.5,
+            }],
+            memory: Memory {
+                total: 1000,
+                available: 400,
+                free: 300,
+                used: 600,
+                swap: Swap {
+                    total: 200,
+                    free: 50,
+                    used: 150,
+                },
+            },
+            network: vec![Network {
+                name: "eth0".to_string(),
+                received: 10,
+                total_received: 100,
+                transmitted: 20,
+                total_transmitted: 200,
+                ..Default::default()
+            }],
+            disk: vec![Disk {
+                file_system: "ext4".to_string(),
+                mount_point: PathBuf::from("/"),
+                total_space: 5000,
+                available_space: 2500,
+            }],
+            processes: vec![Process {
+                pid: crate::Pid::from(1usize),
+                parent: None,
+                name: "init".to_string(),
+                cmd: vec![],
+                cpu_usage: 0.0,
+                memory: 0,
+                virtual_memory: 0,
+                disk_read: 0,
+                disk_written: 0,
+                run_time: 0,
+                status: String::new(),
+            }],
+        };
+        let text = snapshot.to_prometheus();
+        assert!(text.contains("system_cpu_usage{cpu=\"cpu0\"} 12.5"));
+        assert!(text.contains("system_memory_used_bytes 600"));
+        assert!(text.contains("system_swap_total_bytes 200"));
+        assert!(text.contains("system_network_received_bytes{iface=\"eth0\"} 10"));
+        assert!(text.contains("system_network_received_bytes_total{iface=\"eth0\"} 100"));
+        assert!(text.contains("system_disk_available_bytes{mount=\"/\"} 2500"));
+        assert!(text.contains("system_processes 1"));
+    }
+}
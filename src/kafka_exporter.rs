@@ -0,0 +1,141 @@
+//! Kafka streaming exporter.
+//!
+//! Periodically serializes the current snapshots and produces them to a Kafka
+//! topic, so downstream consumers can ingest host metrics as a stream. Each
+//! message carries a timestamp and is keyed by hostname so consumers can
+//! partition by host. Data is pulled through the shared request channel rather
+//! than refreshing independently.
+
+use std::{
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures::channel::oneshot;
+use rdkafka::{
+    config::ClientConfig,
+    producer::{FutureProducer, FutureRecord},
+};
+use serde::Serialize;
+use sysinfo::SystemExt;
+
+use crate::{Cpu, Disk, Memory, Network, Process, Request};
+
+/// Configuration for the Kafka exporter.
+#[derive(Debug, Clone)]
+pub struct KafkaExporterConfig {
+    /// Comma-separated broker list (`bootstrap.servers`).
+    pub brokers: String,
+    /// Topic that snapshots are produced to.
+    pub topic: String,
+    /// Kafka client id.
+    pub client_id: String,
+    /// How often a snapshot is published.
+    pub interval: Duration,
+}
+
+impl Default for KafkaExporterConfig {
+    fn default() -> Self {
+        Self {
+            brokers: "localhost:9092".to_string(),
+            topic: "system-metrics".to_string(),
+            client_id: "system-monitor".to_string(),
+            interval: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Spawns the exporter on a dedicated thread running its own runtime.
+pub(crate) fn spawn(config: KafkaExporterConfig, req_tx: flume::Sender<Request>) {
+    thread::spawn(move || {
+        let runtime = match tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        {
+            Ok(runtime) => runtime,
+            Err(e) => {
+                log::error!("kafka exporter: failed to build runtime: {e}");
+                return;
+            }
+        };
+        runtime.block_on(run(config, req_tx));
+    });
+}
+
+async fn run(config: KafkaExporterConfig, req_tx: flume::Sender<Request>) {
+    let producer: FutureProducer = match ClientConfig::new()
+        .set("bootstrap.servers", &config.brokers)
+        .set("client.id", &config.client_id)
+        .create()
+    {
+        Ok(producer) => producer,
+        Err(e) => {
+            log::error!("kafka exporter: failed to create producer: {e}");
+            return;
+        }
+    };
+    let host = sysinfo::System::new()
+        .host_name()
+        .unwrap_or_else(|| "unknown".to_string());
+
+    loop {
+        let message = Message::collect(&req_tx, &host).await;
+        match serde_json::to_vec(&message) {
+            Ok(payload) => {
+                let record = FutureRecord::to(&config.topic).key(&host).payload(&payload);
+                if let Err((e, _)) = producer.send(record, Duration::from_secs(0)).await {
+                    log::error!("kafka exporter: produce error: {e}");
+                }
+            }
+            Err(e) => log::error!("kafka exporter: serialize error: {e}"),
+        }
+        tokio::time::sleep(config.interval).await;
+    }
+}
+
+#[derive(Serialize)]
+struct Message {
+    timestamp: u64,
+    host: String,
+    cpu: Vec<Cpu>,
+    memory: Memory,
+    network: Vec<Network>,
+    disk: Vec<Disk>,
+    processes: Vec<Process>,
+}
+
+impl Message {
+    async fn collect(req_tx: &flume::Sender<Request>, host: &str) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            host: host.to_string(),
+            cpu: request(req_tx, |tx| Request::Cpu { tx })
+                .await
+                .unwrap_or_default(),
+            memory: request(req_tx, |tx| Request::Memory { tx })
+                .await
+                .unwrap_or_default(),
+            network: request(req_tx, |tx| Request::Network { tx })
+                .await
+                .unwrap_or_default(),
+            disk: request(req_tx, |tx| Request::Disk { tx })
+                .await
+                .unwrap_or_default(),
+            processes: request(req_tx, |tx| Request::Process { tx })
+                .await
+                .unwrap_or_default(),
+        }
+    }
+}
+
+async fn request<T, F>(req_tx: &flume::Sender<Request>, make: F) -> Option<T>
+where
+    F: FnOnce(oneshot::Sender<T>) -> Request,
+{
+    let (tx, rx) = oneshot::channel();
+    req_tx.send_async(make(tx)).await.ok()?;
+    rx.await.ok()
+}
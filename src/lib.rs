@@ -1,16 +1,40 @@
-use std::{path::PathBuf, sync::Arc, thread, time::Duration};
+use std::{
+    collections::VecDeque,
+    fmt,
+    future::Future,
+    path::PathBuf,
+    pin::Pin,
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
 
 use futures::channel::oneshot;
-use parking_lot::RwLock;
-use sysinfo::{CpuExt, DiskExt, NetworkExt, SystemExt};
+use parking_lot::{Mutex, RwLock};
+use sysinfo::{CpuExt, DiskExt, NetworkExt, ProcessExt, SystemExt};
+#[cfg(any(feature = "http-exporter", feature = "kafka-exporter"))]
+use sysinfo::PidExt;
+
+pub use sysinfo::Pid;
+
+#[cfg(feature = "http-exporter")]
+mod http_exporter;
+
+#[cfg(feature = "kafka-exporter")]
+mod kafka_exporter;
+
+#[cfg(feature = "kafka-exporter")]
+pub use kafka_exporter::KafkaExporterConfig;
 
 #[derive(Debug, Default)]
+#[cfg_attr(any(feature = "http-exporter", feature = "kafka-exporter"), derive(serde::Serialize))]
 pub struct Cpu {
     pub name: String,
     pub usage: f32,
 }
 
 #[derive(Debug, Default)]
+#[cfg_attr(any(feature = "http-exporter", feature = "kafka-exporter"), derive(serde::Serialize))]
 pub struct Memory {
     pub total: u64,
     pub available: u64,
@@ -20,6 +44,7 @@ pub struct Memory {
 }
 
 #[derive(Debug, Default)]
+#[cfg_attr(any(feature = "http-exporter", feature = "kafka-exporter"), derive(serde::Serialize))]
 pub struct Swap {
     pub total: u64,
     pub free: u64,
@@ -27,6 +52,7 @@ pub struct Swap {
 }
 
 #[derive(Debug, Default)]
+#[cfg_attr(any(feature = "http-exporter", feature = "kafka-exporter"), derive(serde::Serialize))]
 pub struct Network {
     pub name: String,
     pub received: u64,
@@ -44,6 +70,7 @@ pub struct Network {
 }
 
 #[derive(Debug, Default)]
+#[cfg_attr(any(feature = "http-exporter", feature = "kafka-exporter"), derive(serde::Serialize))]
 pub struct Disk {
     pub file_system: String,
     pub mount_point: PathBuf,
@@ -51,149 +78,703 @@ pub struct Disk {
     pub available_space: u64,
 }
 
+#[derive(Debug, Clone)]
+#[cfg_attr(any(feature = "http-exporter", feature = "kafka-exporter"), derive(serde::Serialize))]
+pub struct Process {
+    #[cfg_attr(any(feature = "http-exporter", feature = "kafka-exporter"), serde(serialize_with = "serialize_pid"))]
+    pub pid: Pid,
+    #[cfg_attr(any(feature = "http-exporter", feature = "kafka-exporter"), serde(serialize_with = "serialize_opt_pid"))]
+    pub parent: Option<Pid>,
+    pub name: String,
+    pub cmd: Vec<String>,
+    pub cpu_usage: f32,
+    pub memory: u64,
+    pub virtual_memory: u64,
+    pub disk_read: u64,
+    pub disk_written: u64,
+    pub run_time: u64,
+    pub status: String,
+}
+
 pub enum Request {
     Cpu { tx: oneshot::Sender<Vec<Cpu>> },
     Memory { tx: oneshot::Sender<Memory> },
     Network { tx: oneshot::Sender<Vec<Network>> },
     Disk { tx: oneshot::Sender<Vec<Disk>> },
+    Process { tx: oneshot::Sender<Vec<Process>> },
+    ProcessByPid { pid: Pid, tx: oneshot::Sender<Option<Process>> },
 }
 
-pub struct SystemMonitor {
-    req_tx: flume::Sender<Request>,
+/// Bounds for a subsystem's adaptive refresh interval.
+///
+/// The controller starts at `base` and, as the underlying readings stay quiet,
+/// grows the target interval towards `max`; when readings become volatile it
+/// shrinks back towards `base`. `min` is the hard floor on the actual sleep
+/// after the measured refresh cost has been subtracted.
+#[derive(Debug, Clone, Copy)]
+pub struct IntervalConfig {
+    pub base: Duration,
+    pub min: Duration,
+    pub max: Duration,
 }
 
-impl SystemMonitor {
+impl IntervalConfig {
+    /// Creates a config anchored at `base`, with a `min` floor a quarter of the
+    /// base and a `max` ceiling ten times the base.
+    pub fn new(base: Duration) -> Self {
+        Self {
+            base,
+            min: base / 4,
+            max: base * 10,
+        }
+    }
+
+    fn bounds(mut self, min: Duration, max: Duration) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+}
+
+/// Builds a [`SystemMonitor`], configuring the refresh cadence of each
+/// subsystem independently.
+pub struct SystemMonitorBuilder {
+    cpu: IntervalConfig,
+    memory: IntervalConfig,
+    network: IntervalConfig,
+    disk: IntervalConfig,
+    process: IntervalConfig,
+    history: usize,
+    #[cfg(feature = "http-exporter")]
+    http_exporter: Option<std::net::SocketAddr>,
+    #[cfg(feature = "kafka-exporter")]
+    kafka: Option<KafkaExporterConfig>,
+}
+
+impl Default for SystemMonitorBuilder {
+    fn default() -> Self {
+        Self {
+            cpu: IntervalConfig::new(sysinfo::System::MINIMUM_CPU_UPDATE_INTERVAL),
+            memory: IntervalConfig::new(Duration::from_secs(10)),
+            network: IntervalConfig::new(Duration::from_millis(500)),
+            disk: IntervalConfig::new(Duration::from_secs(30)),
+            process: IntervalConfig::new(Duration::from_secs(2)),
+            history: 256,
+            #[cfg(feature = "http-exporter")]
+            http_exporter: None,
+            #[cfg(feature = "kafka-exporter")]
+            kafka: None,
+        }
+    }
+}
+
+impl SystemMonitorBuilder {
     pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the base interval at which CPU usage is refreshed.
+    pub fn cpu(mut self, interval: Duration) -> Self {
+        self.cpu = IntervalConfig::new(interval);
+        self
+    }
+
+    /// Sets the adaptive bounds for CPU refreshes.
+    pub fn cpu_bounds(mut self, min: Duration, max: Duration) -> Self {
+        self.cpu = self.cpu.bounds(min, max);
+        self
+    }
+
+    /// Sets the base interval at which memory usage is refreshed.
+    pub fn memory(mut self, interval: Duration) -> Self {
+        self.memory = IntervalConfig::new(interval);
+        self
+    }
+
+    /// Sets the adaptive bounds for memory refreshes.
+    pub fn memory_bounds(mut self, min: Duration, max: Duration) -> Self {
+        self.memory = self.memory.bounds(min, max);
+        self
+    }
+
+    /// Sets the base interval at which network counters are refreshed.
+    pub fn network(mut self, interval: Duration) -> Self {
+        self.network = IntervalConfig::new(interval);
+        self
+    }
+
+    /// Sets the adaptive bounds for network refreshes.
+    pub fn network_bounds(mut self, min: Duration, max: Duration) -> Self {
+        self.network = self.network.bounds(min, max);
+        self
+    }
+
+    /// Sets the base interval at which the disk list is refreshed.
+    pub fn disk(mut self, interval: Duration) -> Self {
+        self.disk = IntervalConfig::new(interval);
+        self
+    }
+
+    /// Sets the adaptive bounds for disk refreshes.
+    pub fn disk_bounds(mut self, min: Duration, max: Duration) -> Self {
+        self.disk = self.disk.bounds(min, max);
+        self
+    }
+
+    /// Sets the base interval at which the process list is refreshed.
+    pub fn process(mut self, interval: Duration) -> Self {
+        self.process = IntervalConfig::new(interval);
+        self
+    }
+
+    /// Sets the adaptive bounds for process refreshes.
+    pub fn process_bounds(mut self, min: Duration, max: Duration) -> Self {
+        self.process = self.process.bounds(min, max);
+        self
+    }
+
+    /// Sets the per-metric history capacity (number of retained samples).
+    pub fn history(mut self, capacity: usize) -> Self {
+        self.history = capacity;
+        self
+    }
+
+    /// Serves the latest metrics over HTTP at `addr`.
+    ///
+    /// The exporter answers `/metrics.json` with the serialized snapshots and
+    /// `/metrics` with Prometheus text-exposition format.
+    #[cfg(feature = "http-exporter")]
+    pub fn http_exporter(mut self, addr: std::net::SocketAddr) -> Self {
+        self.http_exporter = Some(addr);
+        self
+    }
+
+    /// Periodically publishes snapshots to a Kafka topic.
+    #[cfg(feature = "kafka-exporter")]
+    pub fn kafka(mut self, config: KafkaExporterConfig) -> Self {
+        self.kafka = Some(config);
+        self
+    }
+
+    /// Builds the monitor, driving all background work on dedicated OS threads
+    /// via [`ThreadSpawner`].
+    pub fn build(self) -> SystemMonitor {
+        self.spawn_with(ThreadSpawner)
+    }
+
+    /// Builds the monitor, driving the refresh loops and request handler as
+    /// async tasks on `spawner` instead of dedicated OS threads.
+    ///
+    /// A Tokio user can pass [`TokioSpawner`] to get `tokio::spawn`-backed
+    /// timers and an async-native request loop, leaving zero idle OS threads
+    /// while preserving the public `get_*` API. The HTTP and Kafka exporters,
+    /// when configured, still each run on a dedicated OS thread of their own.
+    pub fn spawn_with(self, spawner: impl Spawner) -> SystemMonitor {
         let system = Arc::new(RwLock::new(sysinfo::System::new()));
-        {
-            let cpu_system = system.clone();
-            thread::spawn(move || loop {
-                {
-                    let mut system = cpu_system.write();
-                    system.refresh_cpu();
-                }
-                thread::sleep(sysinfo::System::MINIMUM_CPU_UPDATE_INTERVAL);
-            });
+        let history = History::new(self.history);
+        spawner.spawn(refresh_task(
+            spawner.clone(),
+            system.clone(),
+            self.cpu,
+            history.cpu.clone(),
+            |system| system.refresh_cpu(),
+            cpu_volatility_reading,
+        ));
+        spawner.spawn(refresh_task(
+            spawner.clone(),
+            system.clone(),
+            self.memory,
+            history.memory.clone(),
+            |system| system.refresh_memory(),
+            |system| system.used_memory() as f64,
+        ));
+        spawner.spawn(refresh_task(
+            spawner.clone(),
+            system.clone(),
+            self.disk,
+            history.disk.clone(),
+            |system| {
+                system.refresh_disks_list();
+                system.refresh_disks();
+            },
+            |system| system.disks().iter().map(|d| d.available_space()).sum::<u64>() as f64,
+        ));
+        spawner.spawn(refresh_task(
+            spawner.clone(),
+            system.clone(),
+            self.network,
+            history.network.clone(),
+            |system| {
+                system.refresh_networks_list();
+                system.refresh_networks();
+            },
+            |system| system.networks().into_iter().map(|n| n.1.received()).sum::<u64>() as f64,
+        ));
+        spawner.spawn(refresh_task(
+            spawner.clone(),
+            system.clone(),
+            self.process,
+            history.process.clone(),
+            |system| system.refresh_processes(),
+            |system| system.processes().len() as f64,
+        ));
+        let (req_tx, req_rx) = flume::bounded(1);
+        spawner.spawn(handler_task(system.clone(), req_rx));
+        #[cfg(feature = "http-exporter")]
+        if let Some(addr) = self.http_exporter {
+            http_exporter::spawn(addr, req_tx.clone());
         }
-        {
-            let mem_system = system.clone();
-            thread::spawn(move || loop {
-                {
-                    let mut system = mem_system.write();
-                    system.refresh_memory();
-                }
-                thread::sleep(Duration::from_secs(10));
-            });
+        #[cfg(feature = "kafka-exporter")]
+        if let Some(config) = self.kafka {
+            kafka_exporter::spawn(config, req_tx.clone());
         }
-        {
-            let disk_system = system.clone();
-            thread::spawn(move || loop {
-                {
-                    let mut system = disk_system.write();
-                    system.refresh_disks_list();
-                    system.refresh_disks();
-                }
-                thread::sleep(Duration::from_secs(30));
-            });
+        SystemMonitor {
+            req_tx,
+            regex_cache: Mutex::new(None),
+            history,
         }
-        {
-            let net_system = system.clone();
-            thread::spawn(move || loop {
-                {
-                    let mut system = net_system.write();
-                    system.refresh_networks_list();
-                    system.refresh_networks();
-                }
-                thread::sleep(Duration::from_millis(500));
+    }
+}
+
+fn process_from_sysinfo(p: &sysinfo::Process) -> Process {
+    let disk_usage = p.disk_usage();
+    Process {
+        pid: p.pid(),
+        parent: p.parent(),
+        name: p.name().to_string(),
+        cmd: p.cmd().to_vec(),
+        cpu_usage: p.cpu_usage(),
+        memory: p.memory(),
+        virtual_memory: p.virtual_memory(),
+        disk_read: disk_usage.read_bytes,
+        disk_written: disk_usage.written_bytes,
+        run_time: p.run_time(),
+        status: p.status().to_string(),
+    }
+}
+
+#[cfg(any(feature = "http-exporter", feature = "kafka-exporter"))]
+fn serialize_pid<S>(pid: &Pid, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_u32(pid.as_u32())
+}
+
+#[cfg(any(feature = "http-exporter", feature = "kafka-exporter"))]
+fn serialize_opt_pid<S>(pid: &Option<Pid>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match pid {
+        Some(pid) => serializer.serialize_some(&pid.as_u32()),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn cpu_volatility_reading(system: &sysinfo::System) -> f64 {
+    let cpus = system.cpus();
+    if cpus.is_empty() {
+        return 0.0;
+    }
+    let sum: f32 = cpus.iter().map(|c| c.cpu_usage()).sum();
+    (sum / cpus.len() as f32) as f64
+}
+
+/// A single timestamped reading held in a metric's history ring buffer.
+#[derive(Debug, Clone, Copy)]
+pub struct Sample {
+    pub at: Instant,
+    pub value: f64,
+}
+
+/// Fixed-capacity ring buffer of samples with O(1) push and oldest-first
+/// eviction once full.
+struct RingBuffer {
+    buf: VecDeque<Sample>,
+    capacity: usize,
+}
+
+impl RingBuffer {
+    fn new(capacity: usize) -> Self {
+        Self {
+            buf: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    fn push(&mut self, sample: Sample) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.buf.len() == self.capacity {
+            self.buf.pop_front();
+        }
+        self.buf.push_back(sample);
+    }
+
+    fn within(&self, window: Duration, now: Instant) -> Vec<Sample> {
+        let cutoff = now.checked_sub(window);
+        self.buf
+            .iter()
+            .filter(|s| cutoff.map_or(true, |cutoff| s.at >= cutoff))
+            .copied()
+            .collect()
+    }
+}
+
+/// Per-subsystem history ring buffers, shared between the refresh threads and
+/// the query API.
+#[derive(Clone)]
+struct History {
+    cpu: Arc<Mutex<RingBuffer>>,
+    memory: Arc<Mutex<RingBuffer>>,
+    network: Arc<Mutex<RingBuffer>>,
+    disk: Arc<Mutex<RingBuffer>>,
+    process: Arc<Mutex<RingBuffer>>,
+}
+
+impl History {
+    fn new(capacity: usize) -> Self {
+        let ring = || Arc::new(Mutex::new(RingBuffer::new(capacity)));
+        Self {
+            cpu: ring(),
+            memory: ring(),
+            network: ring(),
+            disk: ring(),
+            process: ring(),
+        }
+    }
+}
+
+/// Adaptive interval controller in the spirit of Garage's "tranquilizer".
+///
+/// The measured refresh cost is subtracted from the target interval so the
+/// cadence stays steady, and an EMA of the relative change between successive
+/// readings grows the target when the system is quiet and shrinks it when the
+/// system is busy, keeping background overhead proportional to volatility.
+struct Tranquilizer {
+    cfg: IntervalConfig,
+    target: Duration,
+    ema: f64,
+    last: Option<f64>,
+}
+
+impl Tranquilizer {
+    // EMA smoothing factor and the watermarks that trigger growth/shrink.
+    const ALPHA: f64 = 0.3;
+    const LOW_WATERMARK: f64 = 0.01;
+    const HIGH_WATERMARK: f64 = 0.1;
+    const GROWTH: u32 = 2;
+
+    fn new(cfg: IntervalConfig) -> Self {
+        Self {
+            cfg,
+            target: cfg.base,
+            ema: 0.0,
+            last: None,
+        }
+    }
+
+    /// Folds a fresh reading into the volatility EMA and retunes the target.
+    fn observe(&mut self, reading: f64) {
+        if let Some(last) = self.last {
+            let denom = last.abs().max(f64::EPSILON);
+            let delta = (reading - last).abs() / denom;
+            self.ema = Self::ALPHA * delta + (1.0 - Self::ALPHA) * self.ema;
+            if self.ema < Self::LOW_WATERMARK {
+                self.target = (self.target * Self::GROWTH).min(self.cfg.max);
+            } else if self.ema > Self::HIGH_WATERMARK {
+                self.target = (self.target / 2).max(self.cfg.base);
+            }
+        }
+        self.last = Some(reading);
+    }
+
+    /// Sleep duration after a refresh that cost `cost`, subtracting the work
+    /// time from the target and clamping to the `min` floor.
+    fn sleep_duration(&self, cost: Duration) -> Duration {
+        self.target.saturating_sub(cost).max(self.cfg.min)
+    }
+}
+
+/// A boxed background task driven by a [`Spawner`].
+pub type BackgroundTask = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// Drives the monitor's background work (the refresh loops and the request
+/// handler) on some executor.
+///
+/// The default [`ThreadSpawner`] gives each task its own OS thread, preserving
+/// the original behaviour. Async-runtime users can instead supply a spawner
+/// that hands the tasks to their executor — see [`SystemMonitorBuilder::spawn_with`].
+pub trait Spawner: Clone + Send + Sync + 'static {
+    fn spawn(&self, task: BackgroundTask);
+
+    /// Returns a future that completes after `duration`, using the spawner's
+    /// own timer so the wait is driven by the same runtime as the tasks.
+    ///
+    /// The default uses a runtime-agnostic timer; executor-specific spawners
+    /// should override this to use their reactor's timer.
+    fn sleep(&self, duration: Duration) -> BackgroundTask {
+        Box::pin(futures_timer::Delay::new(duration))
+    }
+}
+
+/// Default spawner: drives each task to completion on a dedicated OS thread.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ThreadSpawner;
+
+impl Spawner for ThreadSpawner {
+    fn spawn(&self, task: BackgroundTask) {
+        thread::spawn(move || futures::executor::block_on(task));
+    }
+}
+
+/// Spawner that hands tasks to the ambient Tokio runtime, leaving no dedicated
+/// OS threads behind.
+#[cfg(feature = "tokio")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TokioSpawner;
+
+#[cfg(feature = "tokio")]
+impl Spawner for TokioSpawner {
+    fn spawn(&self, task: BackgroundTask) {
+        tokio::spawn(task);
+    }
+
+    fn sleep(&self, duration: Duration) -> BackgroundTask {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+fn refresh_task<S, R, V>(
+    spawner: S,
+    system: Arc<RwLock<sysinfo::System>>,
+    cfg: IntervalConfig,
+    history: Arc<Mutex<RingBuffer>>,
+    mut refresh: R,
+    reading: V,
+) -> BackgroundTask
+where
+    S: Spawner,
+    R: FnMut(&mut sysinfo::System) + Send + 'static,
+    V: Fn(&sysinfo::System) -> f64 + Send + 'static,
+{
+    Box::pin(async move {
+        let mut tranquilizer = Tranquilizer::new(cfg);
+        loop {
+            let start = Instant::now();
+            let sample = {
+                let mut system = system.write();
+                refresh(&mut system);
+                reading(&system)
+            };
+            let cost = start.elapsed();
+            history.lock().push(Sample {
+                at: Instant::now(),
+                value: sample,
             });
+            tranquilizer.observe(sample);
+            spawner.sleep(tranquilizer.sleep_duration(cost)).await;
         }
-        let (req_tx, req_rx) = flume::bounded(1);
-        {
-            let async_system = system.clone();
-            thread::spawn(move || {
-                while let Ok(req) = req_rx.recv() {
-                    let system = async_system.read();
-                    match req {
-                        Request::Cpu { tx } => {
-                            tx.send(
-                                system
-                                    .cpus()
-                                    .iter()
-                                    .map(|c| Cpu {
-                                        name: c.name().to_string(),
-                                        usage: c.cpu_usage(),
-                                    })
-                                    .collect(),
-                            )
-                            .ok();
-                        }
-                        Request::Memory { tx } => {
-                            tx.send(Memory {
-                                total: system.total_memory(),
-                                available: system.available_memory(),
-                                free: system.free_memory(),
-                                used: system.used_memory(),
-                                swap: Swap {
-                                    total: system.total_swap(),
-                                    free: system.free_swap(),
-                                    used: system.used_swap(),
-                                },
+    })
+}
+
+fn handler_task(
+    system: Arc<RwLock<sysinfo::System>>,
+    req_rx: flume::Receiver<Request>,
+) -> BackgroundTask {
+    Box::pin(async move {
+        while let Ok(req) = req_rx.recv_async().await {
+            let system = system.read();
+            match req {
+                Request::Cpu { tx } => {
+                    tx.send(
+                        system
+                            .cpus()
+                            .iter()
+                            .map(|c| Cpu {
+                                name: c.name().to_string(),
+                                usage: c.cpu_usage(),
                             })
-                            .ok();
-                        }
-                        Request::Network { tx } => {
-                            tx.send(
-                                system
-                                    .networks()
-                                    .into_iter()
-                                    .map(|n| Network {
-                                        name: n.0.to_string(),
-                                        received: n.1.received(),
-                                        total_received: n.1.total_received(),
-                                        transmitted: n.1.transmitted(),
-                                        total_transmitted: n.1.total_transmitted(),
-                                        packets_received: n.1.packets_received(),
-                                        total_packets_received: n.1.total_packets_received(),
-                                        packets_transmitted: n.1.packets_transmitted(),
-                                        total_packets_transmitted: n.1.total_packets_transmitted(),
-                                        errors_on_received: n.1.errors_on_received(),
-                                        total_errors_on_received: n.1.total_errors_on_received(),
-                                        errors_on_transmitted: n.1.errors_on_transmitted(),
-                                        total_errors_on_transmitted: n
-                                            .1
-                                            .total_errors_on_transmitted(),
-                                    })
-                                    .collect(),
-                            )
-                            .ok();
-                        }
-                        Request::Disk { tx } => {
-                            tx.send(
-                                system
-                                    .disks()
-                                    .iter()
-                                    .map(|d| Disk {
-                                        file_system: String::from_utf8_lossy(d.file_system())
-                                            .to_string(),
-                                        mount_point: d.mount_point().to_path_buf(),
-                                        total_space: d.total_space(),
-                                        available_space: d.available_space(),
-                                    })
-                                    .collect(),
-                            )
-                            .ok();
-                        }
-                    }
+                            .collect(),
+                    )
+                    .ok();
                 }
-            });
+                Request::Memory { tx } => {
+                    tx.send(Memory {
+                        total: system.total_memory(),
+                        available: system.available_memory(),
+                        free: system.free_memory(),
+                        used: system.used_memory(),
+                        swap: Swap {
+                            total: system.total_swap(),
+                            free: system.free_swap(),
+                            used: system.used_swap(),
+                        },
+                    })
+                    .ok();
+                }
+                Request::Network { tx } => {
+                    tx.send(
+                        system
+                            .networks()
+                            .into_iter()
+                            .map(|n| Network {
+                                name: n.0.to_string(),
+                                received: n.1.received(),
+                                total_received: n.1.total_received(),
+                                transmitted: n.1.transmitted(),
+                                total_transmitted: n.1.total_transmitted(),
+                                packets_received: n.1.packets_received(),
+                                total_packets_received: n.1.total_packets_received(),
+                                packets_transmitted: n.1.packets_transmitted(),
+                                total_packets_transmitted: n.1.total_packets_transmitted(),
+                                errors_on_received: n.1.errors_on_received(),
+                                total_errors_on_received: n.1.total_errors_on_received(),
+                                errors_on_transmitted: n.1.errors_on_transmitted(),
+                                total_errors_on_transmitted: n.1.total_errors_on_transmitted(),
+                            })
+                            .collect(),
+                    )
+                    .ok();
+                }
+                Request::Disk { tx } => {
+                    tx.send(
+                        system
+                            .disks()
+                            .iter()
+                            .map(|d| Disk {
+                                file_system: String::from_utf8_lossy(d.file_system())
+                                    .to_string(),
+                                mount_point: d.mount_point().to_path_buf(),
+                                total_space: d.total_space(),
+                                available_space: d.available_space(),
+                            })
+                            .collect(),
+                    )
+                    .ok();
+                }
+                Request::Process { tx } => {
+                    tx.send(
+                        system
+                            .processes()
+                            .values()
+                            .map(process_from_sysinfo)
+                            .collect(),
+                    )
+                    .ok();
+                }
+                Request::ProcessByPid { pid, tx } => {
+                    tx.send(system.process(pid).map(process_from_sysinfo)).ok();
+                }
+            }
+        }
+    })
+}
+
+/// Matches a process against an already-lowercased substring `needle`.
+fn matches_simple(p: &Process, needle: &str) -> bool {
+    p.name.to_lowercase().contains(needle)
+        || p.cmd.iter().any(|c| c.to_lowercase().contains(needle))
+}
+
+/// Matches a process against a compiled regular expression.
+fn matches_regex(p: &Process, re: &regex::Regex) -> bool {
+    re.is_match(&p.name) || p.cmd.iter().any(|c| re.is_match(c))
+}
+
+/// Returns the pid of the current process, so callers can monitor themselves.
+pub fn current_pid() -> Option<Pid> {
+    sysinfo::get_current_pid().ok()
+}
+
+/// How a [`ProcessQuery`] matches process names and commands.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MatchMode {
+    /// Case-insensitive substring matching. Cheap and allocation-light.
+    #[default]
+    Simple,
+    /// Full regular-expression matching via [`regex::Regex`].
+    Regex,
+}
+
+/// Field used to sort a filtered process list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSort {
+    Cpu,
+    Memory,
+    Pid,
+}
+
+/// A query against the live process list.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessQuery {
+    pub pattern: String,
+    pub mode: MatchMode,
+    pub sort: Option<ProcessSort>,
+}
+
+impl ProcessQuery {
+    /// Creates a simple (substring) query for `pattern`.
+    pub fn simple(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            mode: MatchMode::Simple,
+            sort: None,
         }
-        Self { req_tx }
     }
+
+    /// Creates a regex query for `pattern`.
+    pub fn regex(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            mode: MatchMode::Regex,
+            sort: None,
+        }
+    }
+
+    /// Sorts the result by `sort`.
+    pub fn sorted_by(mut self, sort: ProcessSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// Error returned when a [`ProcessQuery`] cannot be applied.
+#[derive(Debug)]
+pub enum FilterError {
+    /// The regex pattern failed to compile.
+    InvalidRegex(regex::Error),
+}
+
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterError::InvalidRegex(e) => write!(f, "invalid regex: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for FilterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FilterError::InvalidRegex(e) => Some(e),
+        }
+    }
+}
+
+pub struct SystemMonitor {
+    req_tx: flume::Sender<Request>,
+    // Last compiled regex, keyed by its source pattern, so repeated queries
+    // with the same string don't recompile. Only populated in regex mode.
+    regex_cache: Mutex<Option<(String, regex::Regex)>>,
+    history: History,
 }
 
 impl Default for SystemMonitor {
     fn default() -> Self {
-        Self::new()
+        SystemMonitorBuilder::new().build()
     }
 }
 
@@ -221,4 +802,240 @@ impl SystemMonitor {
         self.req_tx.send_async(Request::Disk { tx }).await.ok();
         rx.await.unwrap_or_default()
     }
+
+    pub async fn get_processes(&self) -> Vec<Process> {
+        let (tx, rx) = oneshot::channel();
+        self.req_tx.send_async(Request::Process { tx }).await.ok();
+        rx.await.unwrap_or_default()
+    }
+
+    pub async fn get_process(&self, pid: Pid) -> Option<Process> {
+        let (tx, rx) = oneshot::channel();
+        self.req_tx
+            .send_async(Request::ProcessByPid { pid, tx })
+            .await
+            .ok();
+        rx.await.ok().flatten()
+    }
+
+    /// Returns the processes matching `query`, optionally sorted.
+    ///
+    /// In [`MatchMode::Simple`] the pattern is matched case-insensitively as a
+    /// substring of the process name and command; no regex is compiled. In
+    /// [`MatchMode::Regex`] the pattern is compiled once and cached, so issuing
+    /// the same query repeatedly (e.g. on every keystroke) reuses the compiled
+    /// pattern. An invalid pattern yields [`FilterError::InvalidRegex`].
+    pub async fn get_processes_filtered(
+        &self,
+        query: ProcessQuery,
+    ) -> Result<Vec<Process>, FilterError> {
+        let mut processes = self.get_processes().await;
+        match query.mode {
+            MatchMode::Simple => {
+                let needle = query.pattern.to_lowercase();
+                processes.retain(|p| matches_simple(p, &needle));
+            }
+            MatchMode::Regex => {
+                let re = self.compiled_regex(&query.pattern)?;
+                processes.retain(|p| matches_regex(p, &re));
+            }
+        }
+        if let Some(sort) = query.sort {
+            match sort {
+                ProcessSort::Cpu => processes.sort_by(|a, b| {
+                    b.cpu_usage
+                        .partial_cmp(&a.cpu_usage)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                }),
+                ProcessSort::Memory => processes.sort_by(|a, b| b.memory.cmp(&a.memory)),
+                ProcessSort::Pid => processes.sort_by_key(|p| p.pid),
+            }
+        }
+        Ok(processes)
+    }
+
+    /// Returns the CPU-usage samples recorded within the trailing `window`.
+    pub fn get_cpu_history(&self, window: Duration) -> Vec<Sample> {
+        self.history.cpu.lock().within(window, Instant::now())
+    }
+
+    /// Returns the used-memory samples recorded within the trailing `window`.
+    pub fn get_memory_history(&self, window: Duration) -> Vec<Sample> {
+        self.history.memory.lock().within(window, Instant::now())
+    }
+
+    /// Returns the received-bytes samples recorded within the trailing `window`.
+    pub fn get_network_history(&self, window: Duration) -> Vec<Sample> {
+        self.history.network.lock().within(window, Instant::now())
+    }
+
+    /// Returns the available-space samples recorded within the trailing `window`.
+    pub fn get_disk_history(&self, window: Duration) -> Vec<Sample> {
+        self.history.disk.lock().within(window, Instant::now())
+    }
+
+    /// Returns the process-count samples recorded within the trailing `window`.
+    pub fn get_process_history(&self, window: Duration) -> Vec<Sample> {
+        self.history.process.lock().within(window, Instant::now())
+    }
+
+    fn compiled_regex(&self, pattern: &str) -> Result<regex::Regex, FilterError> {
+        let mut cache = self.regex_cache.lock();
+        if let Some((cached, re)) = cache.as_ref() {
+            if cached == pattern {
+                return Ok(re.clone());
+            }
+        }
+        let re = regex::Regex::new(pattern).map_err(FilterError::InvalidRegex)?;
+        *cache = Some((pattern.to_string(), re.clone()));
+        Ok(re)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(name: &str, cmd: &[&str]) -> Process {
+        Process {
+            pid: Pid::from(1usize),
+            parent: None,
+            name: name.to_string(),
+            cmd: cmd.iter().map(|s| s.to_string()).collect(),
+            cpu_usage: 0.0,
+            memory: 0,
+            virtual_memory: 0,
+            disk_read: 0,
+            disk_written: 0,
+            run_time: 0,
+            status: String::new(),
+        }
+    }
+
+    #[test]
+    fn simple_match_is_case_insensitive_over_name_and_cmd() {
+        let p = process("Firefox", &["/usr/bin/firefox", "--private"]);
+        assert!(matches_simple(&p, "fire"));
+        assert!(matches_simple(&p, "private"));
+        assert!(!matches_simple(&p, "chrome"));
+    }
+
+    #[test]
+    fn regex_match_over_name_and_cmd() {
+        let p = process("nginx", &["nginx", "-g", "daemon off;"]);
+        let re = regex::Regex::new("^ngin.$").unwrap();
+        assert!(matches_regex(&p, &re));
+        let re = regex::Regex::new("daemon").unwrap();
+        assert!(matches_regex(&p, &re));
+        let re = regex::Regex::new("apache").unwrap();
+        assert!(!matches_regex(&p, &re));
+    }
+
+    #[test]
+    fn invalid_regex_is_a_typed_error() {
+        let monitor = SystemMonitor {
+            req_tx: flume::bounded(1).0,
+            regex_cache: Mutex::new(None),
+            history: History::new(0),
+        };
+        assert!(matches!(
+            monitor.compiled_regex("("),
+            Err(FilterError::InvalidRegex(_))
+        ));
+    }
+
+    #[test]
+    fn ring_buffer_evicts_oldest_when_full() {
+        let mut ring = RingBuffer::new(3);
+        let base = Instant::now();
+        for i in 0..5 {
+            ring.push(Sample {
+                at: base,
+                value: i as f64,
+            });
+        }
+        let values: Vec<f64> = ring.buf.iter().map(|s| s.value).collect();
+        assert_eq!(values, vec![2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn ring_buffer_zero_capacity_never_stores() {
+        let mut ring = RingBuffer::new(0);
+        ring.push(Sample {
+            at: Instant::now(),
+            value: 1.0,
+        });
+        assert!(ring.buf.is_empty());
+    }
+
+    fn tuning_config() -> IntervalConfig {
+        IntervalConfig {
+            base: Duration::from_millis(100),
+            min: Duration::from_millis(10),
+            max: Duration::from_millis(800),
+        }
+    }
+
+    #[test]
+    fn tranquilizer_subtracts_cost_and_floors_at_min() {
+        let t = Tranquilizer::new(tuning_config());
+        assert_eq!(
+            t.sleep_duration(Duration::from_millis(30)),
+            Duration::from_millis(70)
+        );
+        assert_eq!(
+            t.sleep_duration(Duration::from_millis(500)),
+            Duration::from_millis(10)
+        );
+    }
+
+    #[test]
+    fn tranquilizer_grows_when_quiet_and_clamps_at_max() {
+        let mut t = Tranquilizer::new(tuning_config());
+        t.observe(5.0); // seeds the baseline, no adjustment
+        assert_eq!(t.target, Duration::from_millis(100));
+        t.observe(5.0);
+        assert_eq!(t.target, Duration::from_millis(200));
+        t.observe(5.0);
+        assert_eq!(t.target, Duration::from_millis(400));
+        t.observe(5.0);
+        assert_eq!(t.target, Duration::from_millis(800));
+        t.observe(5.0); // already at the ceiling
+        assert_eq!(t.target, Duration::from_millis(800));
+    }
+
+    #[test]
+    fn tranquilizer_shrinks_toward_base_when_volatile() {
+        let mut t = Tranquilizer::new(tuning_config());
+        t.observe(5.0);
+        for _ in 0..3 {
+            t.observe(5.0);
+        }
+        assert_eq!(t.target, Duration::from_millis(800));
+        t.observe(100.0); // large relative delta pushes the EMA up
+        assert_eq!(t.target, Duration::from_millis(400));
+        t.observe(1.0);
+        assert_eq!(t.target, Duration::from_millis(200));
+        t.observe(100.0);
+        assert_eq!(t.target, Duration::from_millis(100));
+        t.observe(1.0); // never shrinks below base
+        assert_eq!(t.target, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn ring_buffer_within_filters_by_window() {
+        let mut ring = RingBuffer::new(4);
+        let now = Instant::now();
+        ring.push(Sample {
+            at: now - Duration::from_secs(30),
+            value: 1.0,
+        });
+        ring.push(Sample {
+            at: now - Duration::from_secs(5),
+            value: 2.0,
+        });
+        let recent = ring.within(Duration::from_secs(10), now);
+        let values: Vec<f64> = recent.iter().map(|s| s.value).collect();
+        assert_eq!(values, vec![2.0]);
+    }
 }